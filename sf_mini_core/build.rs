@@ -0,0 +1,86 @@
+use std::env;
+use std::process::Command;
+
+/// Splits `CARGO_PKG_VERSION` (which cargo guarantees is valid semver) into
+/// its `major.minor.patch` core and an optional pre-release tag, and emits
+/// them as compile-time env vars so `sf_core_version_info()` can expose them
+/// without re-parsing the version string at runtime.
+fn emit_version_components() {
+    let version = env::var("CARGO_PKG_VERSION").expect("cargo always sets CARGO_PKG_VERSION");
+    // Semver is `major.minor.patch[-prerelease][+buildmetadata]`; build
+    // metadata, if present, is always last, so it must be stripped before
+    // splitting on `-` or a version like `1.2.3+build5` (valid semver, no
+    // pre-release tag) would wrongly fold "3+build5" into the patch component.
+    let without_build_metadata = version.split_once('+').map_or(version.as_str(), |(v, _)| v);
+    let (core, pre) = match without_build_metadata.split_once('-') {
+        Some((core, pre)) => (core, pre),
+        None => (without_build_metadata, ""),
+    };
+
+    let mut components = core.split('.');
+    let mut next_component = |name: &str| -> u32 {
+        components
+            .next()
+            .unwrap_or_else(|| panic!("CARGO_PKG_VERSION is missing its {name} component"))
+            .parse()
+            .unwrap_or_else(|_| panic!("CARGO_PKG_VERSION's {name} component is not numeric"))
+    };
+    let major = next_component("major");
+    let minor = next_component("minor");
+    let patch = next_component("patch");
+
+    println!("cargo:rustc-env=SF_CORE_VERSION_MAJOR={major}");
+    println!("cargo:rustc-env=SF_CORE_VERSION_MINOR={minor}");
+    println!("cargo:rustc-env=SF_CORE_VERSION_PATCH={patch}");
+    println!("cargo:rustc-env=SF_CORE_VERSION_PRE={pre}");
+}
+
+/// Emits the short git commit hash this build was made from, falling back
+/// to "unknown" when `.git` isn't available (e.g. a source tarball build).
+fn emit_git_commit() {
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=SF_CORE_GIT_COMMIT={commit}");
+}
+
+/// Emits the rustc version string used to compile this build.
+fn emit_rustc_version() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=SF_CORE_RUSTC_VERSION={version}");
+}
+
+/// Emits the build timestamp as Unix seconds, honoring `SOURCE_DATE_EPOCH`
+/// for reproducible builds.
+fn emit_build_timestamp() {
+    let timestamp = env::var("SOURCE_DATE_EPOCH").unwrap_or_else(|_| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs().to_string())
+            .unwrap_or_else(|_| "0".to_string())
+    });
+    println!("cargo:rustc-env=SF_CORE_BUILD_TIMESTAMP={timestamp}");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+}
+
+fn main() {
+    emit_version_components();
+    emit_git_commit();
+    emit_rustc_version();
+    emit_build_timestamp();
+    println!("cargo:rerun-if-changed=build.rs");
+}
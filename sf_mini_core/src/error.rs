@@ -0,0 +1,172 @@
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+
+// This file's protocol lands ahead of the first fallible `sf_core` entry
+// point; until one is wired up, everything below is exercised only from
+// this file's own tests, hence the `#[allow(dead_code)]`s.
+
+/// Error code used when a Rust panic is caught at the FFI boundary instead
+/// of being allowed to unwind into C, which would be undefined behavior.
+#[allow(dead_code)]
+pub const SF_ERROR_PANIC: i32 = -1;
+
+/// The out-parameter every fallible `sf_core` FFI entry point writes its
+/// result into.
+///
+/// On success the callee sets `code = 0` and leaves `message` null. On
+/// failure it sets a nonzero `code` and an owned, heap-allocated,
+/// NUL-terminated UTF-8 `message`. Callers must pass the `message` pointer
+/// (if non-null) to `sf_core_error_free` exactly once; it must never be
+/// freed with anything other than that function, since it was allocated by
+/// Rust's allocator, not C's.
+///
+/// # Safety
+///
+/// `out` must point to a valid, writable `SfError` for the duration of the
+/// call. It is safe to pass a zeroed `SfError` as the initial value.
+#[repr(C)]
+pub struct SfError {
+    pub code: i32,
+    pub message: *mut c_char,
+}
+
+impl SfError {
+    /// Writes a success result (`code = 0`, `message = null`) to `out`.
+    ///
+    /// # Safety
+    ///
+    /// `out` must be null or point to a valid, writable `SfError`.
+    #[allow(dead_code)]
+    unsafe fn write_ok(out: *mut SfError) {
+        if out.is_null() {
+            return;
+        }
+        unsafe {
+            (*out).code = 0;
+            (*out).message = std::ptr::null_mut();
+        }
+    }
+
+    /// Writes a failure result to `out`, allocating an owned C string for
+    /// `message`. Interior NUL bytes in `message` are replaced so the
+    /// allocation always succeeds; diagnostic text should not normally
+    /// contain them.
+    ///
+    /// # Safety
+    ///
+    /// `out` must be null or point to a valid, writable `SfError`.
+    #[allow(dead_code)]
+    unsafe fn write_err(out: *mut SfError, code: i32, message: &str) {
+        if out.is_null() {
+            return;
+        }
+        let sanitized = message.replace('\0', "\u{fffd}");
+        // A CString from a Rust &str can only fail on interior NULs, which
+        // we've just removed, so this is infallible in practice.
+        let c_message = CString::new(sanitized).unwrap_or_default();
+        unsafe {
+            (*out).code = code;
+            (*out).message = c_message.into_raw();
+        }
+    }
+}
+
+/// Frees an `SfError.message` previously populated by an `sf_core`
+/// function. Passing a null pointer is a no-op.
+///
+/// @param message A pointer previously returned in `SfError.message`, or
+///                 null.
+///
+/// # Safety
+///
+/// `message`, if non-null, must be a pointer that was produced by this
+/// crate's error protocol and must not have already been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sf_core_error_free(message: *mut c_char) {
+    if message.is_null() {
+        return;
+    }
+    unsafe {
+        drop(CString::from_raw(message));
+    }
+}
+
+/// Runs `f`, reporting any `Err` or caught panic through the `SfError`
+/// out-parameter.
+///
+/// This is the single entry point every fallible `sf_core` FFI function
+/// should route its body through, so that a Rust panic can never unwind
+/// across the C boundary (which is undefined behavior) and instead becomes
+/// `SF_ERROR_PANIC`.
+#[allow(dead_code)]
+pub(crate) fn call_with_error<F>(out: *mut SfError, f: F)
+where
+    F: FnOnce() -> Result<(), (i32, String)>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => unsafe { SfError::write_ok(out) },
+        Ok(Err((code, message))) => unsafe { SfError::write_err(out, code, &message) },
+        Err(panic_payload) => {
+            let message = panic_payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic_payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic in sf_core".to_string());
+            unsafe { SfError::write_err(out, SF_ERROR_PANIC, &message) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_call_with_error_success() {
+        let mut err = SfError {
+            code: -99,
+            message: std::ptr::null_mut(),
+        };
+        call_with_error(&mut err, || Ok(()));
+        assert_eq!(err.code, 0);
+        assert!(err.message.is_null());
+    }
+
+    #[test]
+    fn test_call_with_error_failure() {
+        let mut err = SfError {
+            code: 0,
+            message: std::ptr::null_mut(),
+        };
+        call_with_error(&mut err, || Err((42, "boom".to_string())));
+        assert_eq!(err.code, 42);
+        assert!(!err.message.is_null());
+        unsafe {
+            assert_eq!(CStr::from_ptr(err.message).to_str().unwrap(), "boom");
+            sf_core_error_free(err.message);
+        }
+    }
+
+    #[test]
+    fn test_call_with_error_panic_is_caught() {
+        let mut err = SfError {
+            code: 0,
+            message: std::ptr::null_mut(),
+        };
+        call_with_error(&mut err, || panic!("oh no"));
+        assert_eq!(err.code, SF_ERROR_PANIC);
+        assert!(!err.message.is_null());
+        unsafe {
+            sf_core_error_free(err.message);
+        }
+    }
+
+    #[test]
+    fn test_sf_core_error_free_null_is_noop() {
+        unsafe {
+            sf_core_error_free(std::ptr::null_mut());
+        }
+    }
+}
@@ -1,10 +1,61 @@
 use std::os::raw::c_char;
 
+mod error;
+mod string;
+mod version;
+
+pub use error::{sf_core_error_free, SfError};
+pub use string::sf_core_string_free;
+pub use version::{sf_core_version_info, SfVersionInfo};
+
+/// The ABI version of sf_core.
+///
+/// This is bumped manually, and only when an exported symbol's signature,
+/// struct layout, or calling convention changes in a breaking way. It is
+/// intentionally decoupled from `sf_core_full_version()`: the display
+/// version can change on every release, but the ABI version only moves
+/// when a host rebuilt against an older header would otherwise corrupt
+/// memory by calling into a newer `.so`.
+const SF_CORE_ABI_VERSION: u32 = 1;
+
+/// Returns the ABI version of sf_core.
+///
+/// The host must call this before invoking any other exported function and
+/// compare the result against the ABI version its bindings were generated
+/// against. On mismatch, the host must abort rather than continue: the
+/// struct layouts and function signatures this library exports are only
+/// guaranteed to match bindings built for the same ABI version.
+///
+/// @return The ABI version as an unsigned 32-bit integer.
+///
+/// @note Thread-safe: Yes
+/// @note This function never fails and never returns a sentinel error value
+///
+/// Example usage:
+/// @code
+///   if (sf_core_abi_version() != SF_CORE_EXPECTED_ABI_VERSION) {
+///       abort();
+///   }
+/// @endcode
+///
+/// # Safety
+///
+/// This function takes no arguments and performs no pointer dereferencing;
+/// it is safe to call from any thread at any time.
+#[unsafe(no_mangle)]
+pub extern "C" fn sf_core_abi_version() -> u32 {
+    SF_CORE_ABI_VERSION
+}
+
 /// Returns the full version string for sf_core.
 ///
 /// This function returns a pointer to a static null-terminated string
 /// containing the version of sf_core.
 ///
+/// This pointer is **borrowed**, not owned: do not pass it to
+/// `sf_core_string_free`, which is only for pointers returned by
+/// owned-pointer string functions.
+///
 /// @return A pointer to a static string containing the version.
 ///         The caller must NOT free this pointer.
 ///         The returned string is valid for the lifetime of the program.
@@ -24,8 +75,9 @@ use std::os::raw::c_char;
 /// of the program. The caller must not free the returned pointer.
 #[unsafe(no_mangle)]
 pub extern "C" fn sf_core_full_version() -> *const c_char {
-    // Static version string - update this as needed
-    static VERSION: &str = "0.0.1\0";
+    // Sourced from the crate's Cargo.toml `version` field, so this and
+    // `sf_core_version_info()` can never drift apart.
+    static VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
     VERSION.as_ptr() as *const c_char
 }
 
@@ -34,6 +86,11 @@ mod tests {
     use super::*;
     use std::ffi::CStr;
 
+    #[test]
+    fn test_sf_core_abi_version() {
+        assert_eq!(sf_core_abi_version(), SF_CORE_ABI_VERSION);
+    }
+
     #[test]
     fn test_sf_core_full_version() {
         let version_ptr = sf_core_full_version();
@@ -43,7 +100,7 @@ mod tests {
             let version_cstr = CStr::from_ptr(version_ptr);
             let version_str = version_cstr.to_str().unwrap();
             assert!(!version_str.is_empty());
-            assert_eq!(version_str, "0.0.1");
+            assert_eq!(version_str, env!("CARGO_PKG_VERSION"));
         }
     }
 }
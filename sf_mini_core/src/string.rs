@@ -0,0 +1,112 @@
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+// This file's helpers land ahead of the first string-returning `sf_core`
+// entry point; until one is wired up, they're exercised only from this
+// file's own tests, hence the `#[allow(dead_code)]`s.
+
+/// Error code returned when a string destined for the C boundary contains
+/// an interior NUL byte and therefore cannot be represented as a
+/// NUL-terminated C string.
+#[allow(dead_code)]
+pub const SF_ERROR_INTERIOR_NUL: i32 = -3;
+
+/// Validates that `s` contains no interior NUL bytes, so it can be safely
+/// turned into a NUL-terminated C string without silently truncating data.
+///
+/// Returns an `(code, message)` pair suitable for `call_with_error` on
+/// failure, rather than truncating at the first NUL.
+#[allow(dead_code)]
+pub(crate) fn validate_no_interior_nul(s: &str) -> Result<(), (i32, String)> {
+    if s.contains('\0') {
+        return Err((
+            SF_ERROR_INTERIOR_NUL,
+            "string contains an interior NUL byte and cannot be returned across the FFI boundary"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Converts an owned Rust `String` into a heap-allocated, NUL-terminated C
+/// string for return across FFI.
+///
+/// The returned pointer is **owned**: the caller must release it with
+/// `sf_core_string_free` exactly once. This is the opposite ownership model
+/// from functions like `sf_core_full_version()`, which return a
+/// `'static`-borrowed pointer that must never be freed.
+///
+/// Returns an error (rather than truncating) if `s` contains an interior
+/// NUL byte.
+#[allow(dead_code)]
+pub(crate) fn into_owned_c_string(s: String) -> Result<*mut c_char, (i32, String)> {
+    validate_no_interior_nul(&s)?;
+    // Interior NULs were just ruled out, so this cannot fail.
+    let c_string = CString::new(s).expect("validated string unexpectedly contained a NUL");
+    Ok(c_string.into_raw())
+}
+
+/// Frees a string previously returned by an **owned-pointer** `sf_core`
+/// function (i.e. any function whose documentation says the caller must
+/// free the result). Passing a null pointer is a no-op.
+///
+/// Do **not** call this on a pointer returned by a function documented as
+/// returning a static, borrowed string (for example
+/// `sf_core_full_version()`) — those pointers are not heap-allocated and
+/// freeing them is undefined behavior.
+///
+/// @param s A pointer previously returned by an owned-pointer `sf_core`
+///          string function, or null.
+///
+/// # Safety
+///
+/// `s`, if non-null, must be a pointer produced by this crate's owned
+/// string helpers and must not have already been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sf_core_string_free(s: *mut c_char) {
+    if s.is_null() {
+        return;
+    }
+    unsafe {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_validate_no_interior_nul_accepts_clean_string() {
+        assert!(validate_no_interior_nul("clean").is_ok());
+    }
+
+    #[test]
+    fn test_validate_no_interior_nul_rejects_interior_nul() {
+        let err = validate_no_interior_nul("bad\0string").unwrap_err();
+        assert_eq!(err.0, SF_ERROR_INTERIOR_NUL);
+    }
+
+    #[test]
+    fn test_into_owned_c_string_round_trips() {
+        let ptr = into_owned_c_string("hello".to_string()).unwrap();
+        unsafe {
+            assert_eq!(CStr::from_ptr(ptr).to_str().unwrap(), "hello");
+            sf_core_string_free(ptr);
+        }
+    }
+
+    #[test]
+    fn test_into_owned_c_string_rejects_interior_nul() {
+        let err = into_owned_c_string("bad\0string".to_string()).unwrap_err();
+        assert_eq!(err.0, SF_ERROR_INTERIOR_NUL);
+    }
+
+    #[test]
+    fn test_sf_core_string_free_null_is_noop() {
+        unsafe {
+            sf_core_string_free(std::ptr::null_mut());
+        }
+    }
+}
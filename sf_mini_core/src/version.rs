@@ -0,0 +1,127 @@
+use std::os::raw::c_char;
+
+/// Structured, parsed version information for sf_core.
+///
+/// `major`, `minor`, and `patch` are parsed at build time (see `build.rs`)
+/// from the crate's `CARGO_PKG_VERSION`, the same source `sf_core_full_version()`
+/// is built from, so the two can never drift apart.
+///
+/// All string fields are static and **borrowed**, exactly like
+/// `sf_core_full_version()`'s return value: the caller must not free them.
+/// `pre_release` is an empty string when the crate version has no
+/// pre-release tag (e.g. the `beta.1` in `1.2.3-beta.1`).
+///
+/// # Safety
+///
+/// This struct has no invariants of its own to uphold when read, but
+/// `sf_core_version_info()` writes through a `*mut SfVersionInfo`, so the
+/// pointer passed to it must point to a valid, writable `SfVersionInfo` for
+/// the duration of the call. It is safe to pass a zeroed `SfVersionInfo` as
+/// the initial value.
+#[repr(C)]
+pub struct SfVersionInfo {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub pre_release: *const c_char,
+    pub git_commit: *const c_char,
+    pub build_timestamp: *const c_char,
+    pub rustc_version: *const c_char,
+}
+
+static PRE_RELEASE: &str = concat!(env!("SF_CORE_VERSION_PRE"), "\0");
+static GIT_COMMIT: &str = concat!(env!("SF_CORE_GIT_COMMIT"), "\0");
+static BUILD_TIMESTAMP: &str = concat!(env!("SF_CORE_BUILD_TIMESTAMP"), "\0");
+static RUSTC_VERSION: &str = concat!(env!("SF_CORE_RUSTC_VERSION"), "\0");
+
+fn parse_version_component(value: &str, name: &str) -> u32 {
+    value
+        .parse()
+        .unwrap_or_else(|_| panic!("build.rs emitted a non-numeric {name} version: {value}"))
+}
+
+/// Fills `out` with structured, parsed version information for sf_core.
+///
+/// Unlike `sf_core_full_version()`, this lets hosts compare versions
+/// programmatically (e.g. `if (info.major < 1) ...`) and log build
+/// provenance without parsing a display string.
+///
+/// @param out A pointer to an `SfVersionInfo` to populate. A null pointer is
+///            a no-op.
+///
+/// @note Thread-safe: Yes
+/// @note All pointer fields are static for the lifetime of the program; the
+///       caller must NOT free them.
+///
+/// Example usage:
+/// @code
+///   SfVersionInfo info;
+///   sf_core_version_info(&info);
+///   printf("%u.%u.%u (%s)\n", info.major, info.minor, info.patch, info.git_commit);
+/// @endcode
+///
+/// # Safety
+///
+/// `out` must be null or point to a valid, writable `SfVersionInfo`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sf_core_version_info(out: *mut SfVersionInfo) {
+    if out.is_null() {
+        return;
+    }
+    unsafe {
+        (*out).major = parse_version_component(env!("SF_CORE_VERSION_MAJOR"), "major");
+        (*out).minor = parse_version_component(env!("SF_CORE_VERSION_MINOR"), "minor");
+        (*out).patch = parse_version_component(env!("SF_CORE_VERSION_PATCH"), "patch");
+        (*out).pre_release = PRE_RELEASE.as_ptr() as *const c_char;
+        (*out).git_commit = GIT_COMMIT.as_ptr() as *const c_char;
+        (*out).build_timestamp = BUILD_TIMESTAMP.as_ptr() as *const c_char;
+        (*out).rustc_version = RUSTC_VERSION.as_ptr() as *const c_char;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_sf_core_version_info_matches_full_version() {
+        let mut info = SfVersionInfo {
+            major: 0,
+            minor: 0,
+            patch: 0,
+            pre_release: std::ptr::null(),
+            git_commit: std::ptr::null(),
+            build_timestamp: std::ptr::null(),
+            rustc_version: std::ptr::null(),
+        };
+        unsafe {
+            sf_core_version_info(&mut info);
+        }
+
+        let expected = format!("{}.{}.{}", info.major, info.minor, info.patch);
+        // Mirror build.rs: strip build metadata (`+...`) before the
+        // pre-release tag (`-...`), since semver build metadata always comes
+        // last in the version string.
+        let version = env!("CARGO_PKG_VERSION");
+        let without_build_metadata = version.split_once('+').map_or(version, |(v, _)| v);
+        assert_eq!(
+            expected,
+            without_build_metadata.split('-').next().unwrap()
+        );
+
+        unsafe {
+            assert!(!info.git_commit.is_null());
+            assert!(!CStr::from_ptr(info.git_commit).to_str().unwrap().is_empty());
+            assert!(!info.rustc_version.is_null());
+            assert!(!info.build_timestamp.is_null());
+        }
+    }
+
+    #[test]
+    fn test_sf_core_version_info_null_out_is_noop() {
+        unsafe {
+            sf_core_version_info(std::ptr::null_mut());
+        }
+    }
+}